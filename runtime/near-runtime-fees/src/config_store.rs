@@ -0,0 +1,154 @@
+//! Protocol-version-keyed storage of [`RuntimeFeesConfig`].
+//!
+//! `RuntimeFeesConfig::default()` bakes in a single set of constants, which makes it impossible to
+//! evolve fees across protocol upgrades while still being able to validate historical blocks
+//! against the parameters that were in force at their height. `RuntimeConfigStore` holds one
+//! config per protocol version that introduced a change, and new versions are expressed as small
+//! JSON overlays on top of the previous version's config rather than full copies.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use near_primitives::types::ProtocolVersion;
+
+use crate::RuntimeFeesConfig;
+
+/// `(protocol_version, diff)` pairs applied in order on top of the genesis config to produce the
+/// config in force at each listed protocol version. Each diff only needs to mention the fields it
+/// changes; fields it omits keep the value from the previous version.
+///
+/// Add an entry here whenever a protocol upgrade changes a fee parameter.
+fn config_diffs() -> Vec<(ProtocolVersion, serde_json::Value)> {
+    vec![(
+        // Protocol version 42 doubled the base cost of a storage write in response to observed
+        // state-growth pressure.
+        42,
+        serde_json::json!({
+            "ext_costs": {
+                "costs": {
+                    "storage_write_base": { "base": 128393472000u64, "per_byte": 0u64 }
+                }
+            }
+        }),
+    )]
+}
+
+/// Merges `diff` into `base` in place: object keys present in `diff` overwrite the corresponding
+/// key in `base` (recursively for nested objects), keys absent from `diff` are left untouched.
+fn merge_json(base: &mut serde_json::Value, diff: &serde_json::Value) {
+    match (base, diff) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(diff_map)) => {
+            for (key, diff_value) in diff_map {
+                merge_json(
+                    base_map.entry(key.clone()).or_insert(serde_json::Value::Null),
+                    diff_value,
+                );
+            }
+        }
+        (base, diff) => *base = diff.clone(),
+    }
+}
+
+fn apply_diff(config: &RuntimeFeesConfig, diff: &serde_json::Value) -> RuntimeFeesConfig {
+    let mut value = serde_json::to_value(config).expect("RuntimeFeesConfig always serializes");
+    merge_json(&mut value, diff);
+    serde_json::from_value(value).expect("diff must only override existing fields with valid values")
+}
+
+/// Holds the [`RuntimeFeesConfig`] in force at each protocol version that changed it.
+#[derive(Debug, Clone)]
+pub struct RuntimeConfigStore {
+    store: BTreeMap<ProtocolVersion, Arc<RuntimeFeesConfig>>,
+}
+
+impl RuntimeConfigStore {
+    /// Builds a store starting from `genesis_runtime_config` at protocol version 0 and applying
+    /// the embedded [`config_diffs`] on top, in order.
+    pub fn new(genesis_runtime_config: RuntimeFeesConfig) -> Self {
+        let mut store = BTreeMap::new();
+        let mut config = genesis_runtime_config;
+        store.insert(0, Arc::new(config.clone()));
+        for (protocol_version, diff) in config_diffs() {
+            config = apply_diff(&config, &diff);
+            store.insert(protocol_version, Arc::new(config.clone()));
+        }
+        Self { store }
+    }
+
+    /// Builds the store for `chain_id`. Different chains can seed different baseline genesis
+    /// parameters before the same version diffs are layered on top.
+    pub fn for_chain_id(chain_id: &str) -> Self {
+        match chain_id {
+            "testnet" => Self::new(Self::testnet_genesis_config()),
+            // "mainnet" and any custom chain id start from the same conservative baseline.
+            _ => Self::new(RuntimeFeesConfig::default()),
+        }
+    }
+
+    /// Testnet seeds the same fee structure as mainnet but with no price floor, so local and CI
+    /// validators can drive the gas price down to zero while iterating instead of inheriting
+    /// mainnet's conservative `min_gas_price`.
+    fn testnet_genesis_config() -> RuntimeFeesConfig {
+        RuntimeFeesConfig { min_gas_price: 0, ..RuntimeFeesConfig::default() }
+    }
+
+    /// The config for the highest protocol version `<= protocol_version`.
+    pub fn get_config(&self, protocol_version: ProtocolVersion) -> &Arc<RuntimeFeesConfig> {
+        self.store
+            .range(..=protocol_version)
+            .next_back()
+            .unwrap_or_else(|| self.store.iter().next().expect("store is never empty"))
+            .1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_config_falls_back_to_highest_version_leq() {
+        let store = RuntimeConfigStore::new(RuntimeFeesConfig::default());
+        let latest = store.get_config(42).clone();
+        assert_eq!(store.get_config(1000), &latest);
+    }
+
+    #[test]
+    fn test_new_does_not_panic_on_default_config_with_large_gas_price_bounds() {
+        // RuntimeFeesConfig::default().max_gas_price exceeds u64::MAX; apply_diff round-trips the
+        // config through serde_json, which used to panic here before min_gas_price/max_gas_price
+        // were gated behind dec_format.
+        let _store = RuntimeConfigStore::new(RuntimeFeesConfig::default());
+        let _mainnet = RuntimeConfigStore::for_chain_id("mainnet");
+        let _testnet = RuntimeConfigStore::for_chain_id("testnet");
+    }
+
+    #[test]
+    fn test_merge_json_overrides_only_mentioned_fields() {
+        let mut base = serde_json::json!({ "a": 1, "b": { "c": 2, "d": 3 } });
+        let diff = serde_json::json!({ "b": { "c": 20 } });
+        merge_json(&mut base, &diff);
+        assert_eq!(base, serde_json::json!({ "a": 1, "b": { "c": 20, "d": 3 } }));
+    }
+
+    #[test]
+    fn test_config_diff_is_applied_from_its_protocol_version_onward() {
+        use crate::ExtCosts;
+
+        let store = RuntimeConfigStore::new(RuntimeFeesConfig::default());
+        let before = store.get_config(41).ext_costs.cost(ExtCosts::storage_write_base, 0);
+        let after = store.get_config(42).ext_costs.cost(ExtCosts::storage_write_base, 0);
+        assert_eq!(before, RuntimeFeesConfig::default().ext_costs.cost(ExtCosts::storage_write_base, 0));
+        assert_eq!(after, 128393472000);
+        assert_ne!(before, after);
+        // The diff persists to later versions until superseded by a further diff.
+        assert_eq!(store.get_config(1000).ext_costs.cost(ExtCosts::storage_write_base, 0), after);
+    }
+
+    #[test]
+    fn test_for_chain_id_seeds_distinct_baselines() {
+        let mainnet = RuntimeConfigStore::for_chain_id("mainnet");
+        let testnet = RuntimeConfigStore::for_chain_id("testnet");
+        assert_ne!(mainnet.get_config(0).min_gas_price, testnet.get_config(0).min_gas_price);
+        assert_eq!(testnet.get_config(0).min_gas_price, 0);
+    }
+}
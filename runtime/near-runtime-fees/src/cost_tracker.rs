@@ -0,0 +1,174 @@
+//! Tracks gas costs while a block/chunk is being filled so scheduling can bound how much work a
+//! single block -- or a single hot account within it -- is allowed to absorb.
+use std::collections::HashMap;
+use std::fmt;
+
+use near_primitives::types::AccountId;
+
+use crate::{Gas, RuntimeFeesConfig};
+
+/// Limits used by [`CostTracker`] to decide when a block is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockCostLimits {
+    /// Total gas a single block is allowed to accumulate across all receipts.
+    pub block_cost_limit: Gas,
+    /// Gas a single account is allowed to accumulate writes for within one block.
+    pub per_account_write_cost_limit: Gas,
+    /// Base cost charged for verifying a single signature, used as the unit limits are derived
+    /// from.
+    pub signature_verification_base_cost: Gas,
+}
+
+impl BlockCostLimits {
+    /// Derives limits from `fees_config`, scaling off the minimum cost of a receipt with a
+    /// function call so the limits stay proportional if fees are retuned.
+    pub fn from_fees_config(fees_config: &RuntimeFeesConfig) -> Self {
+        let unit = fees_config.min_receipt_with_function_call_gas();
+        Self {
+            block_cost_limit: unit * 10_000,
+            per_account_write_cost_limit: unit * 100,
+            signature_verification_base_cost: unit,
+        }
+    }
+}
+
+/// Error returned by [`CostTracker::try_add`] when adding a cost would exceed a configured limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostTrackerError {
+    /// Adding the cost would push the total block cost past `block_cost_limit`.
+    WouldExceedBlockCostLimit,
+    /// Adding the cost would push the account's write cost past `per_account_write_cost_limit`.
+    WouldExceedAccountCostLimit,
+}
+
+impl fmt::Display for CostTrackerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WouldExceedBlockCostLimit => write!(f, "would exceed block cost limit"),
+            Self::WouldExceedAccountCostLimit => write!(f, "would exceed account cost limit"),
+        }
+    }
+}
+
+impl std::error::Error for CostTrackerError {}
+
+/// Accumulates gas costs for a block being filled and rejects further additions once the
+/// configured [`BlockCostLimits`] are reached.
+#[derive(Debug, Clone)]
+pub struct CostTracker {
+    limits: BlockCostLimits,
+    block_cost: Gas,
+    account_write_costs: HashMap<AccountId, Gas>,
+}
+
+impl CostTracker {
+    pub fn new(limits: BlockCostLimits) -> Self {
+        Self { limits, block_cost: 0, account_write_costs: HashMap::new() }
+    }
+
+    /// Current total cost accumulated for the block.
+    pub fn block_cost(&self) -> Gas {
+        self.block_cost
+    }
+
+    /// Current cost accumulated for writes touching `account`.
+    pub fn account_cost(&self, account: &AccountId) -> Gas {
+        self.account_write_costs.get(account).copied().unwrap_or(0)
+    }
+
+    /// Attempts to charge `cost` against the block total and against `account`'s running write
+    /// cost. If either limit would be exceeded, neither counter is modified.
+    pub fn try_add(&mut self, account: &AccountId, cost: Gas) -> Result<(), CostTrackerError> {
+        let new_block_cost =
+            self.block_cost.checked_add(cost).ok_or(CostTrackerError::WouldExceedBlockCostLimit)?;
+        if new_block_cost > self.limits.block_cost_limit {
+            return Err(CostTrackerError::WouldExceedBlockCostLimit);
+        }
+        let current_account_cost = self.account_cost(account);
+        let new_account_cost = current_account_cost
+            .checked_add(cost)
+            .ok_or(CostTrackerError::WouldExceedAccountCostLimit)?;
+        if new_account_cost > self.limits.per_account_write_cost_limit {
+            return Err(CostTrackerError::WouldExceedAccountCostLimit);
+        }
+        self.block_cost = new_block_cost;
+        self.account_write_costs.insert(account.clone(), new_account_cost);
+        Ok(())
+    }
+
+    /// Reverses a previously committed `try_add(account, cost)`.
+    pub fn remove(&mut self, account: &AccountId, cost: Gas) {
+        self.block_cost = self.block_cost.saturating_sub(cost);
+        if let Some(account_cost) = self.account_write_costs.get_mut(account) {
+            *account_cost = account_cost.saturating_sub(cost);
+            if *account_cost == 0 {
+                self.account_write_costs.remove(account);
+            }
+        }
+    }
+
+    /// Clears all accumulated costs, e.g. at the start of a new block.
+    pub fn reset(&mut self) {
+        self.block_cost = 0;
+        self.account_write_costs.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> BlockCostLimits {
+        BlockCostLimits {
+            block_cost_limit: 100,
+            per_account_write_cost_limit: 60,
+            signature_verification_base_cost: 10,
+        }
+    }
+
+    #[test]
+    fn test_try_add_commits_both_counters() {
+        let mut tracker = CostTracker::new(limits());
+        let account: AccountId = "alice.near".parse().unwrap();
+        tracker.try_add(&account, 40).unwrap();
+        assert_eq!(tracker.block_cost(), 40);
+        assert_eq!(tracker.account_cost(&account), 40);
+    }
+
+    #[test]
+    fn test_try_add_rejects_account_limit_without_mutating() {
+        let mut tracker = CostTracker::new(limits());
+        let account: AccountId = "alice.near".parse().unwrap();
+        tracker.try_add(&account, 50).unwrap();
+        let err = tracker.try_add(&account, 20).unwrap_err();
+        assert_eq!(err, CostTrackerError::WouldExceedAccountCostLimit);
+        assert_eq!(tracker.block_cost(), 50);
+        assert_eq!(tracker.account_cost(&account), 50);
+    }
+
+    #[test]
+    fn test_try_add_rejects_block_limit_across_accounts() {
+        let mut tracker = CostTracker::new(limits());
+        let alice: AccountId = "alice.near".parse().unwrap();
+        let bob: AccountId = "bob.near".parse().unwrap();
+        tracker.try_add(&alice, 60).unwrap();
+        let err = tracker.try_add(&bob, 60).unwrap_err();
+        assert_eq!(err, CostTrackerError::WouldExceedBlockCostLimit);
+        assert_eq!(tracker.block_cost(), 60);
+    }
+
+    #[test]
+    fn test_remove_and_reset() {
+        let mut tracker = CostTracker::new(limits());
+        let account: AccountId = "alice.near".parse().unwrap();
+        tracker.try_add(&account, 40).unwrap();
+        tracker.remove(&account, 40);
+        assert_eq!(tracker.block_cost(), 0);
+        assert_eq!(tracker.account_cost(&account), 0);
+
+        tracker.try_add(&account, 10).unwrap();
+        tracker.reset();
+        assert_eq!(tracker.block_cost(), 0);
+        assert_eq!(tracker.account_cost(&account), 0);
+    }
+}
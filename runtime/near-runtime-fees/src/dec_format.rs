@@ -0,0 +1,34 @@
+//! Serde helper for rendering large integers -- in this crate, `Balance` (gas price bounds) -- as
+//! decimal strings in JSON, while still accepting a plain numeric JSON value on the way in.
+//!
+//! JSON numbers are commonly parsed into IEEE 754 doubles, which silently lose precision once a
+//! `u128`/`u64` value exceeds 2^53. Quoting the value as a string sidesteps that entirely, at the
+//! cost of needing an explicit `#[serde(with = "dec_format")]` on the field.
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de;
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<T, S>(num: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.serialize_str(&num.to_string())
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s.parse::<T>().map_err(de::Error::custom),
+        serde_json::Value::Number(n) => {
+            n.to_string().parse::<T>().map_err(de::Error::custom)
+        }
+        _ => Err(de::Error::custom("expected a string or a number")),
+    }
+}
@@ -0,0 +1,139 @@
+//! Per-operation gas costs charged for host functions a contract invokes while executing, as
+//! opposed to the action/receipt creation costs in the rest of this crate.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Gas;
+
+/// A single host-function-metered operation.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(non_camel_case_types)]
+pub enum ExtCosts {
+    /// Base cost for a storage read.
+    storage_read_base,
+    /// Cost per byte of the key for a storage read.
+    storage_read_key_byte,
+    /// Cost per byte of the value returned by a storage read.
+    storage_read_value_byte,
+    /// Base cost for a storage write.
+    storage_write_base,
+    /// Cost per byte of the key for a storage write.
+    storage_write_key_byte,
+    /// Cost per byte of the value for a storage write.
+    storage_write_value_byte,
+    /// Base cost of computing a SHA-256 hash.
+    sha256_base,
+    /// Cost per byte hashed with SHA-256.
+    sha256_byte,
+    /// Base cost of reading from a promise result register.
+    promise_result_base,
+    /// Cost per byte copied out of a promise result register.
+    promise_result_byte,
+    /// Base cost of a contract log call.
+    log_base,
+    /// Cost per byte logged by a contract.
+    log_byte,
+}
+
+/// `base + per_byte * bytes` cost of a single [`ExtCosts`] variant.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ParameterCost {
+    /// Fixed cost charged regardless of the size of the operation.
+    pub base: Gas,
+    /// Additional cost charged per byte processed by the operation.
+    pub per_byte: Gas,
+}
+
+impl ParameterCost {
+    pub fn cost(&self, bytes: u64) -> Gas {
+        self.base + self.per_byte * bytes
+    }
+}
+
+/// Maps each [`ExtCosts`] variant to its [`ParameterCost`].
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
+pub struct ExtCostsConfig {
+    pub costs: BTreeMap<ExtCosts, ParameterCost>,
+}
+
+impl ExtCostsConfig {
+    /// Returns the gas cost of running `ext` over `bytes` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ext` is missing from the config -- every `ExtCosts` variant must have an entry.
+    pub fn cost(&self, ext: ExtCosts, bytes: u64) -> Gas {
+        self.costs[&ext].cost(bytes)
+    }
+}
+
+impl Default for ExtCostsConfig {
+    fn default() -> Self {
+        #[allow(clippy::unreadable_literal)]
+        let costs = vec![
+            (ExtCosts::storage_read_base, ParameterCost { base: 56356845750, per_byte: 0 }),
+            (ExtCosts::storage_read_key_byte, ParameterCost { base: 0, per_byte: 30952533 }),
+            (ExtCosts::storage_read_value_byte, ParameterCost { base: 0, per_byte: 5611005 }),
+            (ExtCosts::storage_write_base, ParameterCost { base: 64196736000, per_byte: 0 }),
+            (ExtCosts::storage_write_key_byte, ParameterCost { base: 0, per_byte: 70482867 }),
+            (ExtCosts::storage_write_value_byte, ParameterCost { base: 0, per_byte: 31018539 }),
+            (ExtCosts::sha256_base, ParameterCost { base: 4540970250, per_byte: 0 }),
+            (ExtCosts::sha256_byte, ParameterCost { base: 0, per_byte: 24117351 }),
+            (ExtCosts::promise_result_base, ParameterCost { base: 800000000, per_byte: 0 }),
+            (ExtCosts::promise_result_byte, ParameterCost { base: 0, per_byte: 76049 }),
+            (ExtCosts::log_base, ParameterCost { base: 3543313050, per_byte: 0 }),
+            (ExtCosts::log_byte, ParameterCost { base: 0, per_byte: 13198791 }),
+        ]
+        .into_iter()
+        .collect();
+        Self { costs }
+    }
+}
+
+impl ExtCostsConfig {
+    pub fn free() -> Self {
+        let costs = [
+            ExtCosts::storage_read_base,
+            ExtCosts::storage_read_key_byte,
+            ExtCosts::storage_read_value_byte,
+            ExtCosts::storage_write_base,
+            ExtCosts::storage_write_key_byte,
+            ExtCosts::storage_write_value_byte,
+            ExtCosts::sha256_base,
+            ExtCosts::sha256_byte,
+            ExtCosts::promise_result_base,
+            ExtCosts::promise_result_byte,
+            ExtCosts::log_base,
+            ExtCosts::log_byte,
+        ]
+        .iter()
+        .map(|ext| (*ext, ParameterCost { base: 0, per_byte: 0 }))
+        .collect();
+        Self { costs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameter_cost() {
+        let cost = ParameterCost { base: 10, per_byte: 2 };
+        assert_eq!(cost.cost(5), 20);
+    }
+
+    #[test]
+    fn test_default_sha256_costs() {
+        let config = ExtCostsConfig::default();
+        assert_eq!(config.cost(ExtCosts::sha256_base, 0), 4540970250);
+        assert_eq!(config.cost(ExtCosts::sha256_byte, 10), 241173510);
+    }
+
+    #[test]
+    fn test_free_is_all_zero() {
+        let config = ExtCostsConfig::free();
+        assert_eq!(config.cost(ExtCosts::storage_write_base, 100), 0);
+    }
+}
@@ -3,9 +3,19 @@
 //! * sir -- sender is receiver. Receipts that are directed by an account to itself are guaranteed
 //!   to not be cross-shard which is cheaper than cross-shard. Conversely, when sender is not a
 //!   receiver it might or might not be a cross-shard communication.
-use num_rational::Rational;
+use near_primitives::types::Balance;
+use num_rational::{Ratio, Rational};
 use serde::{Deserialize, Serialize};
 
+pub mod config_store;
+pub mod cost_tracker;
+pub mod dec_format;
+pub mod ext_costs;
+pub mod views;
+
+pub use config_store::RuntimeConfigStore;
+pub use ext_costs::{ExtCosts, ExtCostsConfig, ParameterCost};
+
 pub type Gas = u64;
 
 /// Costs associated with an object that can only be sent over the network (and executed
@@ -53,11 +63,33 @@ pub struct RuntimeFeesConfig {
     /// Describes fees for storage.
     pub storage_usage_config: StorageUsageConfig,
 
+    /// Describes the costs of the host functions a contract incurs while executing, e.g.
+    /// storage reads/writes, hashing, promise registers and logging.
+    pub ext_costs: ExtCostsConfig,
+
     /// Fraction of the burnt gas to reward to the contract account for execution.
     pub burnt_gas_reward: Rational,
 
     /// Pessimistic gas price inflation ratio.
     pub pessimistic_gas_price_inflation_ratio: Rational,
+
+    /// Target fraction of `limit_gas` a block should use; gas price moves up when the previous
+    /// block used more than this and down when it used less.
+    pub gas_target_utilization: Rational,
+    /// Responsiveness of [`RuntimeFeesConfig::adjusted_gas_price`] to deviation from
+    /// `gas_target_utilization`: how much the gas price moves per unit of relative deviation.
+    pub gas_price_adjustment_rate: Rational,
+    /// Lower bound the adjusted gas price is clamped to.
+    ///
+    /// Serialized through [`dec_format`] rather than as a bare JSON number: a `Balance` can exceed
+    /// `u64::MAX` (and even what `serde_json::to_value` can represent at all), which both breaks
+    /// JSON consumers without native u128 support and makes `serde_json::to_value` return an error.
+    #[serde(with = "dec_format")]
+    pub min_gas_price: Balance,
+    /// Upper bound the adjusted gas price is clamped to. See [`min_gas_price`](Self::min_gas_price)
+    /// for why this is serialized through [`dec_format`].
+    #[serde(with = "dec_format")]
+    pub max_gas_price: Balance,
 }
 
 /// Describes the cost of creating a data receipt, `DataReceipt`.
@@ -212,8 +244,13 @@ impl Default for RuntimeFeesConfig {
                 num_bytes_account: 100,
                 num_extra_bytes_record: 40,
             },
+            ext_costs: ExtCostsConfig::default(),
             burnt_gas_reward: Rational::new(3, 10),
             pessimistic_gas_price_inflation_ratio: Rational::new(103, 100),
+            gas_target_utilization: Rational::new(1, 2),
+            gas_price_adjustment_rate: Rational::new(1, 100),
+            min_gas_price: 100_000_000,
+            max_gas_price: 10_000_000_000_000_000_000_000_u128,
         }
     }
 }
@@ -247,8 +284,13 @@ impl RuntimeFeesConfig {
                 num_bytes_account: 0,
                 num_extra_bytes_record: 0,
             },
+            ext_costs: ExtCostsConfig::free(),
             burnt_gas_reward: Rational::from_integer(0),
             pessimistic_gas_price_inflation_ratio: Rational::from_integer(0),
+            gas_target_utilization: Rational::from_integer(0),
+            gas_price_adjustment_rate: Rational::from_integer(0),
+            min_gas_price: 0,
+            max_gas_price: 0,
         }
     }
 
@@ -260,6 +302,44 @@ impl RuntimeFeesConfig {
         self.action_receipt_creation_config.min_send_and_exec_fee()
             + self.action_creation_config.function_call_cost.min_send_and_exec_fee()
     }
+
+    /// Computes the gas price to use for the next block given `prev_price`, the gas the previous
+    /// block actually used, and `limit_gas`, the max amount of gas a block is allowed to use.
+    ///
+    /// The price moves towards `prev_price * (1 + k * (used_gas - target) / target)`, where
+    /// `target = gas_target_utilization * limit_gas` and `k = gas_price_adjustment_rate`, and is
+    /// clamped to `[min_gas_price, max_gas_price]`. The relative deviation from target is itself
+    /// clamped to `[-1, 1]` so a misconfigured (e.g. near-zero) `gas_target_utilization` can't blow
+    /// up the multiplier. The multiplier is computed with `Ratio<i128>` for determinism, but is
+    /// applied to `prev_price` with `u128` saturating arithmetic rather than by scaling `prev_price`
+    /// itself into the ratio, so an adversarially large `prev_price` saturates instead of
+    /// overflowing.
+    pub fn adjusted_gas_price(&self, prev_price: Balance, used_gas: Gas, limit_gas: Gas) -> Balance {
+        if limit_gas == 0 {
+            return prev_price.max(self.min_gas_price).min(self.max_gas_price);
+        }
+
+        let to_ratio = |r: Rational| Ratio::<i128>::new(*r.numer() as i128, *r.denom() as i128);
+        let target_gas =
+            std::cmp::max((to_ratio(self.gas_target_utilization) * limit_gas as i128).to_integer(), 1);
+        let deviation = Ratio::<i128>::new(used_gas as i128 - target_gas, target_gas);
+        let deviation = deviation
+            .max(Ratio::<i128>::from_integer(-1))
+            .min(Ratio::<i128>::from_integer(1));
+        let multiplier = Ratio::<i128>::from_integer(1) + to_ratio(self.gas_price_adjustment_rate) * deviation;
+
+        // Clamp into range before scaling (rather than scaling then clamping) so the multiply
+        // below can't overflow regardless of what the caller passes as `prev_price`.
+        let prev_price = prev_price.max(self.min_gas_price).min(self.max_gas_price);
+        let new_price = if *multiplier.numer() <= 0 {
+            0
+        } else {
+            let numer = *multiplier.numer() as u128;
+            let denom = *multiplier.denom() as u128;
+            prev_price.saturating_mul(numer) / denom
+        };
+        new_price.max(self.min_gas_price).min(self.max_gas_price)
+    }
 }
 
 #[cfg(test)]
@@ -282,4 +362,41 @@ mod tests {
             "The data receipt cost can't be larger than the cost of a receipt with a function call"
         );
     }
+
+    #[test]
+    fn test_adjusted_gas_price_rises_above_target_utilization() {
+        let config = RuntimeFeesConfig::default();
+        let price = config.adjusted_gas_price(1_000_000_000, 900, 1000);
+        assert!(price > 1_000_000_000);
+    }
+
+    #[test]
+    fn test_adjusted_gas_price_falls_below_target_utilization() {
+        let config = RuntimeFeesConfig::default();
+        let price = config.adjusted_gas_price(1_000_000_000, 100, 1000);
+        assert!(price < 1_000_000_000);
+    }
+
+    #[test]
+    fn test_adjusted_gas_price_unchanged_at_target_utilization() {
+        let config = RuntimeFeesConfig::default();
+        let price = config.adjusted_gas_price(1_000_000_000, 500, 1000);
+        assert_eq!(price, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_adjusted_gas_price_clamped_to_bounds() {
+        let config = RuntimeFeesConfig::default();
+        let price = config.adjusted_gas_price(config.min_gas_price, 0, 1000);
+        assert_eq!(price, config.min_gas_price);
+    }
+
+    #[test]
+    fn test_adjusted_gas_price_does_not_overflow_with_degenerate_target() {
+        let mut config = RuntimeFeesConfig::default();
+        config.gas_target_utilization = Rational::new(1, 1_000_000_000_000);
+        config.max_gas_price = u128::MAX;
+        let price = config.adjusted_gas_price(u128::MAX / 2, Gas::MAX, Gas::MAX);
+        assert!(price <= config.max_gas_price);
+    }
 }
@@ -0,0 +1,295 @@
+//! JSON views of the runtime fee config.
+//!
+//! `RuntimeFeesConfig` and friends derive `Serialize`/`Deserialize` directly, which ties the
+//! on-disk/RPC JSON shape to their internal field layout: any refactor of the cost model becomes a
+//! wire-format break. The types in this module are the stable serialization surface instead --
+//! gas amounts are rendered as plain integers, while any `Balance` (currently just
+//! [`min_gas_price`](RuntimeFeesConfigView::min_gas_price) and
+//! [`max_gas_price`](RuntimeFeesConfigView::max_gas_price)) is rendered through
+//! [`dec_format`](crate::dec_format) as a decimal string, so JSON consumers in languages without
+//! native u128-safe number parsing don't silently lose precision. `StorageUsageConfig`'s fields
+//! are plain byte counts, not balances, so they stay `u64` in the view too.
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+
+use crate::{
+    AccessKeyCreationConfig, ActionCreationConfig, DataReceiptCreationConfig, ExtCosts,
+    ExtCostsConfig, Fee, ParameterCost, RuntimeFeesConfig, StorageUsageConfig,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ParameterCostView {
+    pub base: crate::Gas,
+    pub per_byte: crate::Gas,
+}
+
+impl From<ParameterCost> for ParameterCostView {
+    fn from(cost: ParameterCost) -> Self {
+        Self { base: cost.base, per_byte: cost.per_byte }
+    }
+}
+
+impl From<ParameterCostView> for ParameterCost {
+    fn from(view: ParameterCostView) -> Self {
+        Self { base: view.base, per_byte: view.per_byte }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExtCostsConfigView {
+    pub costs: BTreeMap<ExtCosts, ParameterCostView>,
+}
+
+impl From<ExtCostsConfig> for ExtCostsConfigView {
+    fn from(config: ExtCostsConfig) -> Self {
+        Self { costs: config.costs.into_iter().map(|(ext, cost)| (ext, cost.into())).collect() }
+    }
+}
+
+impl From<ExtCostsConfigView> for ExtCostsConfig {
+    fn from(view: ExtCostsConfigView) -> Self {
+        Self { costs: view.costs.into_iter().map(|(ext, cost)| (ext, cost.into())).collect() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FeeView {
+    pub send_sir: crate::Gas,
+    pub send_not_sir: crate::Gas,
+    pub execution: crate::Gas,
+}
+
+impl From<Fee> for FeeView {
+    fn from(fee: Fee) -> Self {
+        Self { send_sir: fee.send_sir, send_not_sir: fee.send_not_sir, execution: fee.execution }
+    }
+}
+
+impl From<FeeView> for Fee {
+    fn from(view: FeeView) -> Self {
+        Self {
+            send_sir: view.send_sir,
+            send_not_sir: view.send_not_sir,
+            execution: view.execution,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DataReceiptCreationConfigView {
+    pub base_cost: FeeView,
+    pub cost_per_byte: FeeView,
+}
+
+impl From<DataReceiptCreationConfig> for DataReceiptCreationConfigView {
+    fn from(config: DataReceiptCreationConfig) -> Self {
+        Self {
+            base_cost: config.base_cost.into(),
+            cost_per_byte: config.cost_per_byte.into(),
+        }
+    }
+}
+
+impl From<DataReceiptCreationConfigView> for DataReceiptCreationConfig {
+    fn from(view: DataReceiptCreationConfigView) -> Self {
+        Self { base_cost: view.base_cost.into(), cost_per_byte: view.cost_per_byte.into() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AccessKeyCreationConfigView {
+    pub full_access_cost: FeeView,
+    pub function_call_cost: FeeView,
+    pub function_call_cost_per_byte: FeeView,
+}
+
+impl From<AccessKeyCreationConfig> for AccessKeyCreationConfigView {
+    fn from(config: AccessKeyCreationConfig) -> Self {
+        Self {
+            full_access_cost: config.full_access_cost.into(),
+            function_call_cost: config.function_call_cost.into(),
+            function_call_cost_per_byte: config.function_call_cost_per_byte.into(),
+        }
+    }
+}
+
+impl From<AccessKeyCreationConfigView> for AccessKeyCreationConfig {
+    fn from(view: AccessKeyCreationConfigView) -> Self {
+        Self {
+            full_access_cost: view.full_access_cost.into(),
+            function_call_cost: view.function_call_cost.into(),
+            function_call_cost_per_byte: view.function_call_cost_per_byte.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ActionCreationConfigView {
+    pub create_account_cost: FeeView,
+    pub deploy_contract_cost: FeeView,
+    pub deploy_contract_cost_per_byte: FeeView,
+    pub function_call_cost: FeeView,
+    pub function_call_cost_per_byte: FeeView,
+    pub transfer_cost: FeeView,
+    pub stake_cost: FeeView,
+    pub add_key_cost: AccessKeyCreationConfigView,
+    pub delete_key_cost: FeeView,
+    pub delete_account_cost: FeeView,
+}
+
+impl From<ActionCreationConfig> for ActionCreationConfigView {
+    fn from(config: ActionCreationConfig) -> Self {
+        Self {
+            create_account_cost: config.create_account_cost.into(),
+            deploy_contract_cost: config.deploy_contract_cost.into(),
+            deploy_contract_cost_per_byte: config.deploy_contract_cost_per_byte.into(),
+            function_call_cost: config.function_call_cost.into(),
+            function_call_cost_per_byte: config.function_call_cost_per_byte.into(),
+            transfer_cost: config.transfer_cost.into(),
+            stake_cost: config.stake_cost.into(),
+            add_key_cost: config.add_key_cost.into(),
+            delete_key_cost: config.delete_key_cost.into(),
+            delete_account_cost: config.delete_account_cost.into(),
+        }
+    }
+}
+
+impl From<ActionCreationConfigView> for ActionCreationConfig {
+    fn from(view: ActionCreationConfigView) -> Self {
+        Self {
+            create_account_cost: view.create_account_cost.into(),
+            deploy_contract_cost: view.deploy_contract_cost.into(),
+            deploy_contract_cost_per_byte: view.deploy_contract_cost_per_byte.into(),
+            function_call_cost: view.function_call_cost.into(),
+            function_call_cost_per_byte: view.function_call_cost_per_byte.into(),
+            transfer_cost: view.transfer_cost.into(),
+            stake_cost: view.stake_cost.into(),
+            add_key_cost: view.add_key_cost.into(),
+            delete_key_cost: view.delete_key_cost.into(),
+            delete_account_cost: view.delete_account_cost.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct StorageUsageConfigView {
+    pub num_bytes_account: u64,
+    pub num_extra_bytes_record: u64,
+}
+
+impl From<StorageUsageConfig> for StorageUsageConfigView {
+    fn from(config: StorageUsageConfig) -> Self {
+        Self {
+            num_bytes_account: config.num_bytes_account,
+            num_extra_bytes_record: config.num_extra_bytes_record,
+        }
+    }
+}
+
+impl From<StorageUsageConfigView> for StorageUsageConfig {
+    fn from(view: StorageUsageConfigView) -> Self {
+        Self {
+            num_bytes_account: view.num_bytes_account,
+            num_extra_bytes_record: view.num_extra_bytes_record,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct RuntimeFeesConfigView {
+    pub action_receipt_creation_config: FeeView,
+    pub data_receipt_creation_config: DataReceiptCreationConfigView,
+    pub action_creation_config: ActionCreationConfigView,
+    pub storage_usage_config: StorageUsageConfigView,
+    pub ext_costs: ExtCostsConfigView,
+    pub burnt_gas_reward: num_rational::Rational,
+    pub pessimistic_gas_price_inflation_ratio: num_rational::Rational,
+    pub gas_target_utilization: num_rational::Rational,
+    pub gas_price_adjustment_rate: num_rational::Rational,
+    #[serde(with = "crate::dec_format")]
+    pub min_gas_price: near_primitives::types::Balance,
+    #[serde(with = "crate::dec_format")]
+    pub max_gas_price: near_primitives::types::Balance,
+}
+
+impl From<RuntimeFeesConfig> for RuntimeFeesConfigView {
+    fn from(config: RuntimeFeesConfig) -> Self {
+        Self {
+            action_receipt_creation_config: config.action_receipt_creation_config.into(),
+            data_receipt_creation_config: config.data_receipt_creation_config.into(),
+            action_creation_config: config.action_creation_config.into(),
+            storage_usage_config: config.storage_usage_config.into(),
+            ext_costs: config.ext_costs.into(),
+            burnt_gas_reward: config.burnt_gas_reward,
+            pessimistic_gas_price_inflation_ratio: config.pessimistic_gas_price_inflation_ratio,
+            gas_target_utilization: config.gas_target_utilization,
+            gas_price_adjustment_rate: config.gas_price_adjustment_rate,
+            min_gas_price: config.min_gas_price,
+            max_gas_price: config.max_gas_price,
+        }
+    }
+}
+
+impl From<RuntimeFeesConfigView> for RuntimeFeesConfig {
+    fn from(view: RuntimeFeesConfigView) -> Self {
+        Self {
+            action_receipt_creation_config: view.action_receipt_creation_config.into(),
+            data_receipt_creation_config: view.data_receipt_creation_config.into(),
+            action_creation_config: view.action_creation_config.into(),
+            storage_usage_config: view.storage_usage_config.into(),
+            ext_costs: view.ext_costs.into(),
+            burnt_gas_reward: view.burnt_gas_reward,
+            pessimistic_gas_price_inflation_ratio: view.pessimistic_gas_price_inflation_ratio,
+            gas_target_utilization: view.gas_target_utilization,
+            gas_price_adjustment_rate: view.gas_price_adjustment_rate,
+            min_gas_price: view.min_gas_price,
+            max_gas_price: view.max_gas_price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_through_view() {
+        let config = RuntimeFeesConfig::default();
+        let view: RuntimeFeesConfigView = config.clone().into();
+        let restored: RuntimeFeesConfig = view.into();
+        assert_eq!(config, restored);
+    }
+
+    #[test]
+    fn test_view_serializes_gas_as_plain_integer() {
+        let view: RuntimeFeesConfigView = RuntimeFeesConfig::default().into();
+        let json = serde_json::to_value(&view).unwrap();
+        assert!(json["action_receipt_creation_config"]["send_sir"].is_number());
+    }
+
+    #[test]
+    fn test_view_serializes_gas_price_as_decimal_string() {
+        let view: RuntimeFeesConfigView = RuntimeFeesConfig::default().into();
+        let json = serde_json::to_value(&view).unwrap();
+        assert!(json["min_gas_price"].is_string());
+        assert_eq!(json["min_gas_price"], view.min_gas_price.to_string());
+    }
+
+    #[test]
+    fn test_view_deserializes_gas_price_from_plain_number() {
+        let mut json = serde_json::to_value(RuntimeFeesConfigView::from(RuntimeFeesConfig::default()))
+            .unwrap();
+        json["min_gas_price"] = serde_json::json!(12345);
+        let view: RuntimeFeesConfigView = serde_json::from_value(json).unwrap();
+        assert_eq!(view.min_gas_price, 12345);
+    }
+
+    #[test]
+    fn test_ext_costs_config_view_roundtrips() {
+        let config = ExtCostsConfig::default();
+        let view: ExtCostsConfigView = config.clone().into();
+        let restored: ExtCostsConfig = view.into();
+        assert_eq!(config, restored);
+    }
+}